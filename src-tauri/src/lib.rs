@@ -17,7 +17,9 @@ fn greet(name: &str) -> String {
 #[tauri::command]
 fn enable_wallpaper_mode(window: tauri::Window) -> Result<(), String> {
     let hwnd = window.hwnd().map_err(|e| e.to_string())?;
-    wallpaper::set_as_wallpaper(hwnd.0 as isize).map_err(|e| e.to_string())
+    wallpaper::set_as_wallpaper(hwnd.0 as isize).map_err(|e| e.to_string())?;
+    wallpaper::start_watcher(window.app_handle().clone());
+    Ok(())
 }
 
 #[cfg(not(windows))]
@@ -26,11 +28,44 @@ fn enable_wallpaper_mode(_window: tauri::Window) -> Result<(), String> {
     Err("Wallpaper mode is only supported on Windows".to_string())
 }
 
+#[cfg(windows)]
+#[tauri::command]
+fn set_as_wallpaper_on_monitor(window: tauri::Window, monitor_index: usize) -> Result<(), String> {
+    let hwnd = window.hwnd().map_err(|e| e.to_string())?;
+    wallpaper::set_as_wallpaper_on_monitor(hwnd.0 as isize, monitor_index)
+        .map_err(|e| e.to_string())?;
+    wallpaper::start_watcher(window.app_handle().clone());
+    Ok(())
+}
+
+#[cfg(not(windows))]
+#[tauri::command]
+fn set_as_wallpaper_on_monitor(_window: tauri::Window, _monitor_index: usize) -> Result<(), String> {
+    Err("Wallpaper mode is only supported on Windows".to_string())
+}
+
+#[cfg(windows)]
+#[tauri::command]
+fn set_skip_taskbar(window: tauri::Window, skip: bool) -> Result<(), String> {
+    let hwnd = window.hwnd().map_err(|e| e.to_string())?;
+    wallpaper::set_skip_taskbar(hwnd.0 as isize, skip).map_err(|e| e.to_string())
+}
+
+#[cfg(not(windows))]
+#[tauri::command]
+fn set_skip_taskbar(_window: tauri::Window, _skip: bool) -> Result<(), String> {
+    Err("Wallpaper mode is only supported on Windows".to_string())
+}
+
 #[cfg(windows)]
 #[tauri::command]
 fn disable_wallpaper_mode(window: tauri::Window) -> Result<(), String> {
     let hwnd = window.hwnd().map_err(|e| e.to_string())?;
-    wallpaper::restore_window(hwnd.0 as isize).map_err(|e| e.to_string())
+    wallpaper::restore_window(hwnd.0 as isize).map_err(|e| e.to_string())?;
+    if wallpaper::active_windows().is_empty() {
+        wallpaper::stop_watcher();
+    }
+    Ok(())
 }
 
 #[cfg(not(windows))]
@@ -41,13 +76,14 @@ fn disable_wallpaper_mode(_window: tauri::Window) -> Result<(), String> {
 
 #[cfg(windows)]
 #[tauri::command]
-fn is_wallpaper_mode() -> bool {
-    wallpaper::is_wallpaper_mode()
+fn is_wallpaper_mode(window: tauri::Window) -> bool {
+    let hwnd = window.hwnd().ok().map(|h| h.0 as isize);
+    wallpaper::is_wallpaper_mode(hwnd)
 }
 
 #[cfg(not(windows))]
 #[tauri::command]
-fn is_wallpaper_mode() -> bool {
+fn is_wallpaper_mode(_window: tauri::Window) -> bool {
     false
 }
 
@@ -86,10 +122,12 @@ pub fn run() {
                         if let Some(window) = app.get_webview_window("main") {
                             #[cfg(windows)]
                             {
-                                if wallpaper::is_wallpaper_mode() {
-                                    let hwnd = window.hwnd().unwrap();
-                                    let _ = wallpaper::restore_window(hwnd.0 as isize);
+                                // Restore every active wallpaper window, not just main —
+                                // set_as_wallpaper_on_monitor may have pinned others too
+                                for hwnd in wallpaper::active_windows() {
+                                    let _ = wallpaper::restore_window(hwnd);
                                 }
+                                wallpaper::stop_watcher();
                             }
                             let _ = window.show();
                             let _ = window.set_focus();
@@ -102,6 +140,7 @@ pub fn run() {
                             {
                                 let hwnd = window.hwnd().unwrap();
                                 let _ = wallpaper::set_as_wallpaper(hwnd.0 as isize);
+                                wallpaper::start_watcher(app.clone());
                                 let _ = window.emit("wallpaper-mode-changed", true);
                             }
                         }
@@ -109,12 +148,11 @@ pub fn run() {
                     "exit" => {
                         #[cfg(windows)]
                         {
-                            if let Some(window) = app.get_webview_window("main") {
-                                if wallpaper::is_wallpaper_mode() {
-                                    let hwnd = window.hwnd().unwrap();
-                                    let _ = wallpaper::restore_window(hwnd.0 as isize);
-                                }
+                            // Restore every window still pinned to the desktop before quitting
+                            for hwnd in wallpaper::active_windows() {
+                                let _ = wallpaper::restore_window(hwnd);
                             }
+                            wallpaper::stop_watcher();
                         }
                         app.exit(0);
                     }
@@ -127,6 +165,8 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             greet,
             enable_wallpaper_mode,
+            set_as_wallpaper_on_monitor,
+            set_skip_taskbar,
             disable_wallpaper_mode,
             is_wallpaper_mode,
             get_monitors,
@@ -1,10 +1,15 @@
 #![cfg(windows)]
 
 use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
+use std::thread::JoinHandle;
+use tauri::{AppHandle, Emitter};
 use thiserror::Error;
 use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Gdi::*;
+use windows::Win32::UI::HiDpi::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 
 #[derive(Error, Debug)]
@@ -23,28 +28,68 @@ pub enum WallpaperError {
     NotWallpaperMode,
     #[error("Windows API error: {0}")]
     WindowsApi(String),
+    #[error("Monitor index {0} not found")]
+    MonitorNotFound(usize),
 }
 
 type WallpaperResult<T> = std::result::Result<T, WallpaperError>;
 
+/// Where a wallpaper window is pinned, so its rect can be recomputed after a
+/// resolution/monitor/DPI change instead of replaying a stale cached rect
+#[derive(Debug, Clone, Copy)]
+enum WallpaperTarget {
+    /// Spans the full virtual screen (all monitors)
+    Virtual,
+    /// A single physical monitor, by its `MonitorInfo::index`
+    Monitor(usize),
+}
+
+impl Default for WallpaperTarget {
+    fn default() -> Self {
+        WallpaperTarget::Virtual
+    }
+}
+
+/// Resolve a `WallpaperTarget` to its current screen rect
+fn resolve_target_rect(target: WallpaperTarget) -> RECT {
+    match target {
+        WallpaperTarget::Virtual => {
+            let (vx, vy, vw, vh) = get_virtual_screen();
+            RECT {
+                left: vx,
+                top: vy,
+                right: vx + vw,
+                bottom: vy + vh,
+            }
+        }
+        WallpaperTarget::Monitor(index) => get_all_monitors()
+            .get(index)
+            .map(|monitor| RECT {
+                left: monitor.x,
+                top: monitor.y,
+                right: monitor.x + monitor.width,
+                bottom: monitor.y + monitor.height,
+            })
+            .unwrap_or_else(|| resolve_target_rect(WallpaperTarget::Virtual)),
+    }
+}
+
 #[derive(Default)]
 struct WallpaperState {
-    is_active: bool,
     original_parent: Option<isize>,
     original_style: i32,
     original_ex_style: i32,
     original_rect: Option<RECT>,
     worker_w: Option<isize>,
+    /// Where this window is pinned, so the watcher can recompute its rect on reattach
+    target: WallpaperTarget,
 }
 
-static STATE: Mutex<WallpaperState> = Mutex::new(WallpaperState {
-    is_active: false,
-    original_parent: None,
-    original_style: 0,
-    original_ex_style: 0,
-    original_rect: None,
-    worker_w: None,
-});
+/// Saved state for every HWND currently pinned to the desktop, keyed by HWND
+static STATE: Mutex<HashMap<isize, WallpaperState>> = Mutex::new(HashMap::new());
+
+static WATCHER_RUNNING: AtomicBool = AtomicBool::new(false);
+static WATCHER_HANDLE: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
 
 #[derive(Debug, Clone, Serialize)]
 pub struct MonitorInfo {
@@ -54,6 +99,21 @@ pub struct MonitorInfo {
     pub width: i32,
     pub height: i32,
     pub is_primary: bool,
+    pub dpi: u32,
+    pub scale_factor: f64,
+}
+
+/// Per-monitor DPI via Shcore, falling back to 96 (100%) if unavailable
+fn monitor_dpi(hmonitor: HMONITOR) -> u32 {
+    let mut dpi_x = 0u32;
+    let mut dpi_y = 0u32;
+    unsafe {
+        if GetDpiForMonitor(hmonitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y).is_ok() {
+            dpi_x
+        } else {
+            96
+        }
+    }
 }
 
 /// Find the Progman window (Program Manager)
@@ -127,15 +187,16 @@ fn get_virtual_screen() -> (i32, i32, i32, i32) {
     }
 }
 
-/// Set the window as desktop wallpaper
-pub fn set_as_wallpaper(hwnd: isize) -> WallpaperResult<()> {
+/// Pin a window to the desktop, sizing it to cover `target`
+fn set_as_wallpaper_internal(hwnd: isize, target: WallpaperTarget) -> WallpaperResult<()> {
     let mut state = STATE.lock().unwrap();
 
-    if state.is_active {
+    if state.contains_key(&hwnd) {
         return Err(WallpaperError::AlreadyWallpaperMode);
     }
 
     let window = HWND(hwnd as *mut _);
+    let target_rect = resolve_target_rect(target);
 
     // Find Progman and spawn WorkerW
     let progman = find_progman()?;
@@ -146,65 +207,82 @@ pub fn set_as_wallpaper(hwnd: isize) -> WallpaperResult<()> {
 
     let worker_w = find_worker_w()?;
 
+    let mut window_state = WallpaperState::default();
+
     unsafe {
         // Store original parent
         let parent_result = GetParent(window);
-        state.original_parent = Some(parent_result.unwrap_or(HWND(std::ptr::null_mut())).0 as isize);
+        window_state.original_parent =
+            Some(parent_result.unwrap_or(HWND(std::ptr::null_mut())).0 as isize);
 
         // Store original window style
-        state.original_style = GetWindowLongW(window, GWL_STYLE);
-        state.original_ex_style = GetWindowLongW(window, GWL_EXSTYLE);
+        window_state.original_style = GetWindowLongW(window, GWL_STYLE);
+        window_state.original_ex_style = GetWindowLongW(window, GWL_EXSTYLE);
 
         // Store original window rect
         let mut rect = RECT::default();
         let _ = GetWindowRect(window, &mut rect);
-        state.original_rect = Some(rect);
+        window_state.original_rect = Some(rect);
 
         // Remove window decorations
-        let new_style = state.original_style & !(WS_CAPTION.0 as i32)
+        let new_style = window_state.original_style & !(WS_CAPTION.0 as i32)
             & !(WS_THICKFRAME.0 as i32)
             & !(WS_MINIMIZEBOX.0 as i32)
             & !(WS_MAXIMIZEBOX.0 as i32)
             & !(WS_SYSMENU.0 as i32);
         SetWindowLongW(window, GWL_STYLE, new_style);
 
-        // Remove extended styles
-        let new_ex_style = state.original_ex_style & !(WS_EX_DLGMODALFRAME.0 as i32)
+        // Remove extended styles, and make the window non-activating and
+        // invisible to the taskbar/Alt-Tab so it behaves like desktop background
+        let new_ex_style = window_state.original_ex_style & !(WS_EX_DLGMODALFRAME.0 as i32)
             & !(WS_EX_CLIENTEDGE.0 as i32)
-            & !(WS_EX_STATICEDGE.0 as i32);
+            & !(WS_EX_STATICEDGE.0 as i32)
+            & !(WS_EX_APPWINDOW.0 as i32)
+            | WS_EX_NOACTIVATE.0 as i32
+            | WS_EX_TOOLWINDOW.0 as i32;
         SetWindowLongW(window, GWL_EXSTYLE, new_ex_style);
 
         // Set WorkerW as parent
         let _ = SetParent(window, Some(worker_w));
 
-        // Get virtual screen size (spans all monitors)
-        let (vx, vy, vw, vh) = get_virtual_screen();
-
-        // Resize window to cover all monitors
+        // Resize window to cover the target monitor/virtual-screen rect
         let _ = SetWindowPos(
             window,
             Some(HWND_TOP),
-            vx,
-            vy,
-            vw,
-            vh,
+            target_rect.left,
+            target_rect.top,
+            target_rect.right - target_rect.left,
+            target_rect.bottom - target_rect.top,
             SWP_FRAMECHANGED | SWP_SHOWWINDOW,
         );
 
-        state.worker_w = Some(worker_w.0 as isize);
-        state.is_active = true;
+        window_state.worker_w = Some(worker_w.0 as isize);
     }
 
+    window_state.target = target;
+    state.insert(hwnd, window_state);
+
     Ok(())
 }
 
-/// Restore window to normal mode
+/// Set the window as desktop wallpaper, spanning every connected monitor
+pub fn set_as_wallpaper(hwnd: isize) -> WallpaperResult<()> {
+    set_as_wallpaper_internal(hwnd, WallpaperTarget::Virtual)
+}
+
+/// Set the window as desktop wallpaper on a single physical monitor
+pub fn set_as_wallpaper_on_monitor(hwnd: isize, monitor_index: usize) -> WallpaperResult<()> {
+    if monitor_index >= get_all_monitors().len() {
+        return Err(WallpaperError::MonitorNotFound(monitor_index));
+    }
+    set_as_wallpaper_internal(hwnd, WallpaperTarget::Monitor(monitor_index))
+}
+
+/// Restore a single window to normal mode
 pub fn restore_window(hwnd: isize) -> WallpaperResult<()> {
     let mut state = STATE.lock().unwrap();
 
-    if !state.is_active {
-        return Err(WallpaperError::NotWallpaperMode);
-    }
+    let window_state = state.remove(&hwnd).ok_or(WallpaperError::NotWallpaperMode)?;
 
     let window = HWND(hwnd as *mut _);
 
@@ -213,11 +291,11 @@ pub fn restore_window(hwnd: isize) -> WallpaperResult<()> {
         let _ = SetParent(window, None);
 
         // Restore original style
-        SetWindowLongW(window, GWL_STYLE, state.original_style);
-        SetWindowLongW(window, GWL_EXSTYLE, state.original_ex_style);
+        SetWindowLongW(window, GWL_STYLE, window_state.original_style);
+        SetWindowLongW(window, GWL_EXSTYLE, window_state.original_ex_style);
 
         // Restore original position and size
-        if let Some(rect) = state.original_rect {
+        if let Some(rect) = window_state.original_rect {
             let _ = SetWindowPos(
                 window,
                 Some(HWND_TOP),
@@ -230,62 +308,213 @@ pub fn restore_window(hwnd: isize) -> WallpaperResult<()> {
         }
     }
 
-    // Reset state
-    state.is_active = false;
-    state.original_parent = None;
-    state.original_rect = None;
-    state.worker_w = None;
-
     Ok(())
 }
 
-/// Check if currently in wallpaper mode
-pub fn is_wallpaper_mode() -> bool {
-    STATE.lock().unwrap().is_active
+/// Check whether `hwnd` is pinned to the desktop, or whether any window is if `hwnd` is `None`
+pub fn is_wallpaper_mode(hwnd: Option<isize>) -> bool {
+    let state = STATE.lock().unwrap();
+    match hwnd {
+        Some(hwnd) => state.contains_key(&hwnd),
+        None => !state.is_empty(),
+    }
 }
 
-/// Get information about all connected monitors
-pub fn get_all_monitors() -> Vec<MonitorInfo> {
-    let mut monitors: Vec<MonitorInfo> = Vec::new();
+/// HWNDs of all windows currently pinned to the desktop
+pub fn active_windows() -> Vec<isize> {
+    STATE.lock().unwrap().keys().copied().collect()
+}
+
+/// Start the background watcher that keeps wallpaper windows attached to a live
+/// WorkerW across Explorer restarts and display changes. No-op if already running.
+pub fn start_watcher(app: AppHandle) {
+    if WATCHER_RUNNING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    let handle = std::thread::spawn(move || {
+        // Per-monitor rects and DPI, so a rearrangement or DPI change inside the
+        // same virtual-screen bounds is still detected
+        let monitor_layout = || -> Vec<(i32, i32, i32, i32, u32)> {
+            get_all_monitors()
+                .iter()
+                .map(|m| (m.x, m.y, m.width, m.height, m.dpi))
+                .collect()
+        };
+        let mut last_layout = monitor_layout();
 
+        while WATCHER_RUNNING.load(Ordering::SeqCst) {
+            std::thread::sleep(std::time::Duration::from_secs(2));
+
+            if !WATCHER_RUNNING.load(Ordering::SeqCst) {
+                break;
+            }
+
+            // Snapshot the active windows and release STATE before touching Win32 —
+            // spawn_worker_w()/find_worker_w() can block for up to ~1s and must not
+            // stall a concurrent set_as_wallpaper()/restore_window() call.
+            let windows: Vec<(isize, WallpaperTarget, Option<isize>)> = {
+                let state = STATE.lock().unwrap();
+                state
+                    .iter()
+                    .map(|(hwnd, w)| (*hwnd, w.target, w.worker_w))
+                    .collect()
+            };
+
+            if windows.is_empty() {
+                continue;
+            }
+
+            let current_layout = monitor_layout();
+            let display_changed = current_layout != last_layout;
+            last_layout = current_layout;
+
+            let worker_w_alive = windows[0].2.is_some_and(|worker_w| unsafe {
+                IsWindow(Some(HWND(worker_w as *mut _))).as_bool()
+            });
+
+            // Nothing to do unless Explorer dropped our WorkerW or the display
+            // layout (resolution/monitor topology/DPI) changed under us
+            if worker_w_alive && !display_changed {
+                continue;
+            }
+
+            let new_worker_w = if worker_w_alive {
+                windows[0].2.map(|worker_w| HWND(worker_w as *mut _))
+            } else {
+                // WorkerW was destroyed (Explorer restart) — find the fresh one
+                let Ok(progman) = find_progman() else {
+                    continue;
+                };
+                if spawn_worker_w(progman).is_err() {
+                    continue;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(100));
+                find_worker_w().ok()
+            };
+
+            let Some(new_worker_w) = new_worker_w else {
+                continue;
+            };
+
+            for (hwnd, target, _) in &windows {
+                let window = HWND(*hwnd as *mut _);
+                let rect = resolve_target_rect(*target);
+                unsafe {
+                    if !worker_w_alive {
+                        let _ = SetParent(window, Some(new_worker_w));
+                    }
+                    let _ = SetWindowPos(
+                        window,
+                        Some(HWND_TOP),
+                        rect.left,
+                        rect.top,
+                        rect.right - rect.left,
+                        rect.bottom - rect.top,
+                        SWP_FRAMECHANGED | SWP_SHOWWINDOW,
+                    );
+                }
+            }
+
+            // Re-lock only to record the (possibly unchanged) WorkerW handle
+            {
+                let mut state = STATE.lock().unwrap();
+                for (hwnd, ..) in &windows {
+                    if let Some(window_state) = state.get_mut(hwnd) {
+                        window_state.worker_w = Some(new_worker_w.0 as isize);
+                    }
+                }
+            }
+
+            let _ = app.emit("wallpaper-reattached", ());
+        }
+    });
+
+    *WATCHER_HANDLE.lock().unwrap() = Some(handle);
+}
+
+/// Stop the background watcher; call once the last wallpaper window is restored
+pub fn stop_watcher() {
+    if !WATCHER_RUNNING.swap(false, Ordering::SeqCst) {
+        return;
+    }
+    if let Some(handle) = WATCHER_HANDLE.lock().unwrap().take() {
+        let _ = handle.join();
+    }
+}
+
+/// Toggle taskbar/Alt-Tab visibility for a window independent of wallpaper mode
+pub fn set_skip_taskbar(hwnd: isize, skip: bool) -> WallpaperResult<()> {
+    let window = HWND(hwnd as *mut _);
+
+    unsafe {
+        let ex_style = GetWindowLongW(window, GWL_EXSTYLE);
+        let new_ex_style = if skip {
+            (ex_style | WS_EX_TOOLWINDOW.0 as i32) & !(WS_EX_APPWINDOW.0 as i32)
+        } else {
+            (ex_style | WS_EX_APPWINDOW.0 as i32) & !(WS_EX_TOOLWINDOW.0 as i32)
+        };
+        SetWindowLongW(window, GWL_EXSTYLE, new_ex_style);
+
+        // Force the taskbar to pick up the extended style change
+        let _ = SetWindowPos(
+            window,
+            None,
+            0,
+            0,
+            0,
+            0,
+            SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_FRAMECHANGED,
+        );
+    }
+
+    Ok(())
+}
+
+/// `EnumDisplayMonitors` callback: append one `MonitorInfo` per physical display
+extern "system" fn enum_monitor_callback(
+    hmonitor: HMONITOR,
+    _hdc: HDC,
+    _clip_rect: *mut RECT,
+    lparam: LPARAM,
+) -> BOOL {
     unsafe {
-        // Get primary monitor info first
-        let desktop = GetDesktopWindow();
-        let primary = MonitorFromWindow(desktop, MONITOR_DEFAULTTOPRIMARY);
+        let monitors = &mut *(lparam.0 as *mut Vec<MonitorInfo>);
+
         let mut mi = MONITORINFO {
             cbSize: std::mem::size_of::<MONITORINFO>() as u32,
             ..Default::default()
         };
 
-        if GetMonitorInfoW(primary, &mut mi).as_bool() {
+        if GetMonitorInfoW(hmonitor, &mut mi).as_bool() {
+            let dpi = monitor_dpi(hmonitor);
             monitors.push(MonitorInfo {
-                index: 0,
+                index: monitors.len(),
                 x: mi.rcMonitor.left,
                 y: mi.rcMonitor.top,
                 width: mi.rcMonitor.right - mi.rcMonitor.left,
                 height: mi.rcMonitor.bottom - mi.rcMonitor.top,
-                is_primary: true,
+                is_primary: mi.dwFlags & MONITORINFOF_PRIMARY != 0,
+                dpi,
+                scale_factor: dpi as f64 / 96.0,
             });
         }
+    }
 
-        // For multi-monitor, we use the virtual screen dimensions
-        let (vx, vy, vw, vh) = get_virtual_screen();
-
-        // If virtual screen is larger than primary, we have multiple monitors
-        if let Some(primary_info) = monitors.first() {
-            if vw > primary_info.width || vh > primary_info.height || vx < 0 || vy < 0 {
-                // Clear and add virtual screen info
-                monitors.clear();
-                monitors.push(MonitorInfo {
-                    index: 0,
-                    x: vx,
-                    y: vy,
-                    width: vw,
-                    height: vh,
-                    is_primary: true,
-                });
-            }
-        }
+    BOOL(1)
+}
+
+/// Get information about all connected monitors
+pub fn get_all_monitors() -> Vec<MonitorInfo> {
+    let mut monitors: Vec<MonitorInfo> = Vec::new();
+
+    unsafe {
+        let _ = EnumDisplayMonitors(
+            None,
+            None,
+            Some(enum_monitor_callback),
+            LPARAM(&mut monitors as *mut _ as isize),
+        );
     }
 
     monitors